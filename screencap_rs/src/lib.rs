@@ -6,8 +6,12 @@
 use once_cell::sync::Lazy;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 use xcap::{Monitor, Window};
 
 // ============================================================================
@@ -128,20 +132,121 @@ impl MonitorInfo {
     }
 }
 
-/// Captured window with image data
+/// Raw RGBA8 pixel buffer exposed to Python via the buffer protocol, so
+/// NumPy/Pillow can wrap it (e.g. `np.frombuffer(...)`) without copying.
+#[pyclass]
+#[derive(Clone)]
+pub struct RawFrame {
+    pixels: Vec<u8>,
+    #[pyo3(get)]
+    width: u32,
+    #[pyo3(get)]
+    height: u32,
+    #[pyo3(get)]
+    stride: usize,
+}
+
+#[pymethods]
+impl RawFrame {
+    fn __repr__(&self) -> String {
+        format!(
+            "RawFrame(width={}, height={}, stride={}, bytes={})",
+            self.width,
+            self.height,
+            self.stride,
+            self.pixels.len()
+        )
+    }
+
+    unsafe fn __getbuffer__(
+        slf: PyRefMut<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(pyo3::exceptions::PyBufferError::new_err("view is null"));
+        }
+        if (flags & pyo3::ffi::PyBUF_WRITABLE) == pyo3::ffi::PyBUF_WRITABLE {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "RawFrame buffer is read-only",
+            ));
+        }
+
+        let bytes = slf.pixels.as_ptr();
+        let len = slf.pixels.len();
+
+        (*view).obj = slf.into_ptr();
+        (*view).buf = bytes as *mut std::os::raw::c_void;
+        (*view).len = len as isize;
+        (*view).readonly = 1;
+        (*view).itemsize = 1;
+        (*view).format = if (flags & pyo3::ffi::PyBUF_FORMAT) == pyo3::ffi::PyBUF_FORMAT {
+            let format = std::ffi::CString::new("B").unwrap();
+            format.into_raw()
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).ndim = 1;
+        (*view).shape = if (flags & pyo3::ffi::PyBUF_ND) == pyo3::ffi::PyBUF_ND {
+            &mut (*view).len
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).strides = if (flags & pyo3::ffi::PyBUF_STRIDES) == pyo3::ffi::PyBUF_STRIDES {
+            &mut (*view).itemsize
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).suboffsets = std::ptr::null_mut();
+        (*view).internal = std::ptr::null_mut();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, view: *mut pyo3::ffi::Py_buffer) {
+        if !(*view).format.is_null() {
+            drop(std::ffi::CString::from_raw((*view).format));
+        }
+    }
+}
+
+fn image_to_raw_frame(image: &image::RgbaImage) -> RawFrame {
+    let width = image.width();
+    let height = image.height();
+    RawFrame {
+        pixels: image.as_raw().clone(),
+        width,
+        height,
+        stride: width as usize * 4,
+    }
+}
+
+/// Captured window with image data. Encoding is lazy: the raw pixels are
+/// kept around and only turned into bytes when `get_image_bytes` is called.
 #[pyclass]
 #[derive(Clone)]
 pub struct CapturedWindow {
     #[pyo3(get)]
     pub info: WindowInfo,
-    image_data: Vec<u8>,
+    pixels: image::RgbaImage,
+    format: ImageFormat,
+    quality: Option<u8>,
 }
 
 #[pymethods]
 impl CapturedWindow {
-    /// Get image as PNG bytes
-    fn get_image_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
-        PyBytes::new(py, &self.image_data)
+    /// Get image bytes, encoded in the format requested at capture time
+    fn get_image_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let image_data = image_to_bytes(&self.pixels, self.format, self.quality)
+            .map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
+        Ok(PyBytes::new(py, &image_data))
+    }
+
+    /// Get the raw RGBA8 pixel buffer with no encoding. The pixels are copied
+    /// into a new `RawFrame`, whose buffer Python can then wrap without a
+    /// further copy.
+    fn get_image_raw(&self) -> RawFrame {
+        image_to_raw_frame(&self.pixels)
     }
 
     #[getter]
@@ -156,32 +261,49 @@ impl CapturedWindow {
 
     fn __repr__(&self) -> String {
         format!(
-            "CapturedWindow(app='{}', title='{}', bytes={})",
-            self.info.app_name, self.info.title, self.image_data.len()
+            "CapturedWindow(app='{}', title='{}', size={}x{})",
+            self.info.app_name,
+            self.info.title,
+            self.pixels.width(),
+            self.pixels.height()
         )
     }
 }
 
-/// Captured screen with image data
+/// Captured screen with image data. Encoding is lazy: the raw pixels are
+/// kept around and only turned into bytes when `get_image_bytes` is called.
 #[pyclass]
 #[derive(Clone)]
 pub struct CapturedScreen {
     #[pyo3(get)]
     pub monitor: MonitorInfo,
-    image_data: Vec<u8>,
+    pixels: image::RgbaImage,
+    format: ImageFormat,
+    quality: Option<u8>,
 }
 
 #[pymethods]
 impl CapturedScreen {
-    /// Get image as PNG bytes
-    fn get_image_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
-        PyBytes::new(py, &self.image_data)
+    /// Get image bytes, encoded in the format requested at capture time
+    fn get_image_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let image_data = image_to_bytes(&self.pixels, self.format, self.quality)
+            .map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
+        Ok(PyBytes::new(py, &image_data))
+    }
+
+    /// Get the raw RGBA8 pixel buffer with no encoding. The pixels are copied
+    /// into a new `RawFrame`, whose buffer Python can then wrap without a
+    /// further copy.
+    fn get_image_raw(&self) -> RawFrame {
+        image_to_raw_frame(&self.pixels)
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "CapturedScreen(monitor='{}', bytes={})",
-            self.monitor.name, self.image_data.len()
+            "CapturedScreen(monitor='{}', size={}x{})",
+            self.monitor.name,
+            self.pixels.width(),
+            self.pixels.height()
         )
     }
 }
@@ -222,6 +344,33 @@ fn window_to_info(window: &Window) -> WindowInfo {
     }
 }
 
+/// Resolve a monitor by id, or the primary monitor when `monitor_id` is `None`
+fn find_monitor(monitors: &[Monitor], monitor_id: Option<u32>) -> PyResult<(usize, &Monitor)> {
+    if let Some(id) = monitor_id {
+        monitors.iter().enumerate().find(|(_, m)| m.id() == id)
+    } else {
+        monitors.first().map(|m| (0, m))
+    }
+    .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("No monitor found"))
+}
+
+/// Resolve the monitor whose bounds contain `(x, y)` (virtual-screen
+/// coordinates), falling back to the primary monitor if none contains it —
+/// e.g. the point lies in a gap between differently-sized monitors.
+fn find_monitor_for_point(monitors: &[Monitor], x: i32, y: i32) -> PyResult<(usize, &Monitor)> {
+    monitors
+        .iter()
+        .enumerate()
+        .find(|(_, m)| {
+            x >= m.x()
+                && x < m.x() + m.width() as i32
+                && y >= m.y()
+                && y < m.y() + m.height() as i32
+        })
+        .or_else(|| monitors.first().map(|m| (0, m)))
+        .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("No monitor found"))
+}
+
 fn monitor_to_info(monitor: &Monitor, index: u32) -> MonitorInfo {
     MonitorInfo {
         id: monitor.id(),
@@ -234,15 +383,430 @@ fn monitor_to_info(monitor: &Monitor, index: u32) -> MonitorInfo {
     }
 }
 
-fn image_to_png_bytes(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
+/// Output format for encoded captures, exposed to Python as `screencap_rs.ImageFormat`.
+///
+/// `quality` is only honored for `Jpeg` — PNG and WebP are always encoded
+/// lossless (the `image` crate's WebP encoder only supports the lossless
+/// path; there is no lossy/quality knob to pass through).
+#[pyclass]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Jpeg,
+    WebP,
+}
+
+fn image_to_bytes(
+    image: &image::RgbaImage,
+    format: ImageFormat,
+    quality: Option<u8>,
+) -> Result<Vec<u8>, String> {
     let mut buffer = Cursor::new(Vec::new());
     let dynamic_image = image::DynamicImage::ImageRgba8(image.clone());
-    dynamic_image
-        .write_to(&mut buffer, image::ImageFormat::Png)
-        .map_err(|e| format!("Failed to encode image: {}", e))?;
+
+    match format {
+        ImageFormat::Png => dynamic_image
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .map_err(|e| format!("Failed to encode image: {}", e))?,
+        ImageFormat::Jpeg => {
+            let quality = quality.unwrap_or(85);
+            let mut encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buffer, quality);
+            encoder
+                .encode_image(&dynamic_image.to_rgb8())
+                .map_err(|e| format!("Failed to encode image: {}", e))?;
+        }
+        ImageFormat::WebP => {
+            let encoder = image::codecs::webp::WebPEncoder::new_lossless(&mut buffer);
+            encoder
+                .encode(image.as_raw(), image.width(), image.height(), image::ExtendedColorType::Rgba8)
+                .map_err(|e| format!("Failed to encode image: {}", e))?;
+        }
+    }
+
     Ok(buffer.into_inner())
 }
 
+// ============================================================================
+// Active window detection
+// ============================================================================
+
+/// Resolve the native id of the currently focused window, if any.
+#[cfg(target_os = "linux")]
+fn active_window_id() -> PyResult<Option<u32>> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    let (conn, screen_num) = x11rb::connect(None).map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to connect to X11: {}", e))
+    })?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let net_active_window = conn
+        .intern_atom(false, b"_NET_ACTIVE_WINDOW")
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .reply()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .atom;
+
+    let reply = conn
+        .get_property(false, root, net_active_window, AtomEnum::WINDOW, 0, 1)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .reply()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(reply.value32().and_then(|mut v| v.next()))
+}
+
+#[cfg(target_os = "windows")]
+fn active_window_id() -> PyResult<Option<u32>> {
+    use winapi::um::winuser::GetForegroundWindow;
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(hwnd as u32))
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn active_window_id() -> PyResult<Option<u32>> {
+    use core_foundation::array::CFArray;
+    use core_foundation::base::TCFType;
+    use core_foundation::dictionary::CFDictionary;
+    use core_foundation::number::CFNumber;
+    use core_foundation::string::CFString;
+    use core_graphics::window::{
+        kCGNullWindowID, kCGWindowListOptionOnScreenOnly, kCGWindowNumber, kCGWindowLayer,
+        CGWindowListCopyWindowInfo,
+    };
+
+    let info: CFArray<CFDictionary<CFString, CFNumber>> = unsafe {
+        CFArray::wrap_under_create_rule(CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly,
+            kCGNullWindowID,
+        ))
+    };
+
+    // The frontmost on-screen window is the first entry at layer 0.
+    for entry in info.iter() {
+        let layer = entry
+            .find(unsafe { CFString::wrap_under_get_rule(kCGWindowLayer) })
+            .and_then(|n| n.to_i64())
+            .unwrap_or(-1);
+        if layer != 0 {
+            continue;
+        }
+        if let Some(number) = entry.find(unsafe { CFString::wrap_under_get_rule(kCGWindowNumber) })
+        {
+            return Ok(number.to_i64().map(|n| n as u32));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+fn active_window_id() -> PyResult<Option<u32>> {
+    Ok(None)
+}
+
+// ============================================================================
+// Window state control
+// ============================================================================
+
+#[cfg(target_os = "linux")]
+fn send_net_active_window(window_id: u32) -> PyResult<()> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ClientMessageEvent, ConnectionExt, EventMask};
+
+    let (conn, screen_num) = x11rb::connect(None).map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to connect to X11: {}", e))
+    })?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let net_active_window = conn
+        .intern_atom(false, b"_NET_ACTIVE_WINDOW")
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .reply()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .atom;
+
+    // source indication 1 = application, per the EWMH spec.
+    let event = ClientMessageEvent::new(
+        32,
+        window_id,
+        net_active_window,
+        [1, 0, 0, 0, 0],
+    );
+
+    conn.send_event(
+        false,
+        root,
+        EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+        event,
+    )
+    .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    conn.flush()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn set_wm_state(window_id: u32, iconic: bool) -> PyResult<()> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ClientMessageEvent, ConnectionExt, EventMask};
+
+    const NORMAL_STATE: u32 = 1;
+    const ICONIC_STATE: u32 = 3;
+
+    let (conn, screen_num) = x11rb::connect(None).map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to connect to X11: {}", e))
+    })?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let wm_change_state = conn
+        .intern_atom(false, b"WM_CHANGE_STATE")
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .reply()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .atom;
+
+    let state = if iconic { ICONIC_STATE } else { NORMAL_STATE };
+    let event = ClientMessageEvent::new(32, window_id, wm_change_state, [state, 0, 0, 0, 0]);
+
+    conn.send_event(
+        false,
+        root,
+        EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+        event,
+    )
+    .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    conn.flush()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    // Restoring also means raising and focusing the window again.
+    if !iconic {
+        send_net_active_window(window_id)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn set_foreground(window_id: u32) -> PyResult<()> {
+    use winapi::um::winuser::SetForegroundWindow;
+
+    let hwnd = window_id as winapi::shared::windef::HWND;
+    let ok = unsafe { SetForegroundWindow(hwnd) };
+    if ok == 0 {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(
+            "SetForegroundWindow failed",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn show_window(window_id: u32, minimize: bool) -> PyResult<()> {
+    use winapi::um::winuser::{ShowWindow, SW_MINIMIZE, SW_RESTORE};
+
+    let hwnd = window_id as winapi::shared::windef::HWND;
+    let cmd = if minimize { SW_MINIMIZE } else { SW_RESTORE };
+    unsafe { ShowWindow(hwnd, cmd) };
+    if !minimize {
+        set_foreground(window_id)?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn ax_error_to_string(err: accessibility_sys::AXError) -> String {
+    format!("AXError({})", err)
+}
+
+/// Resolve the AXUIElement *window* (not the application) for a CGWindowID.
+///
+/// macOS has no public API to resolve a CGWindowID directly to an
+/// AXUIElement, so we look up the owning process, ask it for its windows via
+/// `kAXWindowsAttribute`, and take the frontmost one — AX already returns
+/// that attribute z-ordered front-to-back. The caller owns the returned
+/// reference and must `CFRelease` it.
+#[cfg(target_os = "macos")]
+fn ax_window_for_id(window_id: u32) -> PyResult<accessibility_sys::AXUIElementRef> {
+    use core_foundation::array::CFArray;
+    use core_foundation::base::{CFRelease, TCFType};
+    use core_foundation::dictionary::CFDictionary;
+    use core_foundation::number::CFNumber;
+    use core_foundation::string::CFString;
+    use core_graphics::window::{
+        kCGWindowListOptionIncludingWindow, kCGWindowOwnerPID, CGWindowListCopyWindowInfo,
+    };
+    use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex};
+
+    let info: CFArray<CFDictionary<CFString, CFNumber>> = unsafe {
+        CFArray::wrap_under_create_rule(CGWindowListCopyWindowInfo(
+            kCGWindowListOptionIncludingWindow,
+            window_id,
+        ))
+    };
+
+    let pid = info
+        .iter()
+        .next()
+        .and_then(|entry| entry.find(unsafe { CFString::wrap_under_get_rule(kCGWindowOwnerPID) }))
+        .and_then(|n| n.to_i64())
+        .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("Window not found"))?;
+
+    let app = unsafe { accessibility_sys::AXUIElementCreateApplication(pid as i32) };
+    if app.is_null() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(
+            "Failed to create AXUIElement for owning application",
+        ));
+    }
+
+    let windows_attr = CFString::new(accessibility_sys::kAXWindowsAttribute);
+    let mut windows_ref: core_foundation::base::CFTypeRef = std::ptr::null();
+    let err = unsafe {
+        accessibility_sys::AXUIElementCopyAttributeValue(
+            app,
+            windows_attr.as_concrete_TypeRef(),
+            &mut windows_ref,
+        )
+    };
+
+    if err != accessibility_sys::kAXErrorSuccess || windows_ref.is_null() {
+        unsafe { CFRelease(app as core_foundation::base::CFTypeRef) };
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "AXUIElementCopyAttributeValue(kAXWindowsAttribute) failed: {}",
+            ax_error_to_string(err)
+        )));
+    }
+    // App-level lookup is done; only the window list (and, below, one
+    // element from it) is still needed.
+    unsafe { CFRelease(app as core_foundation::base::CFTypeRef) };
+
+    let windows_array = windows_ref as core_foundation_sys::array::CFArrayRef;
+    let count = unsafe { CFArrayGetCount(windows_array) };
+    if count == 0 {
+        unsafe { CFRelease(windows_ref) };
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(
+            "Application has no AX windows",
+        ));
+    }
+
+    let window = unsafe { CFArrayGetValueAtIndex(windows_array, 0) } as accessibility_sys::AXUIElementRef;
+    let window = unsafe { core_foundation::base::CFRetain(window as core_foundation::base::CFTypeRef) }
+        as accessibility_sys::AXUIElementRef;
+    unsafe { CFRelease(windows_ref) };
+
+    Ok(window)
+}
+
+#[cfg(target_os = "macos")]
+fn set_minimized(window_id: u32, minimized: bool) -> PyResult<()> {
+    use accessibility_sys::{
+        kAXErrorSuccess, kAXMinimizedAttribute, kAXRaiseAction, AXUIElementPerformAction,
+        AXUIElementSetAttributeValue,
+    };
+    use core_foundation::base::{CFRelease, TCFType};
+    use core_foundation::boolean::CFBoolean;
+    use core_foundation::string::CFString;
+
+    let window = ax_window_for_id(window_id)?;
+
+    let attribute = CFString::new(kAXMinimizedAttribute);
+    let value = CFBoolean::from(minimized);
+    let err =
+        unsafe { AXUIElementSetAttributeValue(window, attribute.as_concrete_TypeRef(), value.as_CFTypeRef()) };
+    if err != kAXErrorSuccess {
+        unsafe { CFRelease(window as core_foundation::base::CFTypeRef) };
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "AXUIElementSetAttributeValue(kAXMinimizedAttribute) failed: {}",
+            ax_error_to_string(err)
+        )));
+    }
+
+    if !minimized {
+        let action = CFString::new(kAXRaiseAction);
+        let err = unsafe { AXUIElementPerformAction(window, action.as_concrete_TypeRef()) };
+        if err != kAXErrorSuccess {
+            unsafe { CFRelease(window as core_foundation::base::CFTypeRef) };
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "AXUIElementPerformAction(kAXRaiseAction) failed: {}",
+                ax_error_to_string(err)
+            )));
+        }
+    }
+
+    unsafe { CFRelease(window as core_foundation::base::CFTypeRef) };
+    Ok(())
+}
+
+/// Bring a window to the front and give it input focus
+#[pyfunction]
+fn focus_window(window_id: u32) -> PyResult<()> {
+    #[cfg(target_os = "linux")]
+    return send_net_active_window(window_id);
+
+    #[cfg(target_os = "windows")]
+    return set_foreground(window_id);
+
+    #[cfg(target_os = "macos")]
+    return set_minimized(window_id, false);
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+    {
+        let _ = window_id;
+        Err(pyo3::exceptions::PyNotImplementedError::new_err(
+            "focus_window is not supported on this platform",
+        ))
+    }
+}
+
+/// Minimize a window
+#[pyfunction]
+fn minimize_window(window_id: u32) -> PyResult<()> {
+    #[cfg(target_os = "linux")]
+    return set_wm_state(window_id, true);
+
+    #[cfg(target_os = "windows")]
+    return show_window(window_id, true);
+
+    #[cfg(target_os = "macos")]
+    return set_minimized(window_id, true);
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+    {
+        let _ = window_id;
+        Err(pyo3::exceptions::PyNotImplementedError::new_err(
+            "minimize_window is not supported on this platform",
+        ))
+    }
+}
+
+/// Restore a minimized window and raise it
+#[pyfunction]
+fn restore_window(window_id: u32) -> PyResult<()> {
+    #[cfg(target_os = "linux")]
+    return set_wm_state(window_id, false);
+
+    #[cfg(target_os = "windows")]
+    return show_window(window_id, false);
+
+    #[cfg(target_os = "macos")]
+    return set_minimized(window_id, false);
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+    {
+        let _ = window_id;
+        Err(pyo3::exceptions::PyNotImplementedError::new_err(
+            "restore_window is not supported on this platform",
+        ))
+    }
+}
+
 // ============================================================================
 // Python-exposed functions
 // ============================================================================
@@ -302,9 +866,28 @@ fn get_windows(include_minimized: bool, filter_system: bool) -> PyResult<Vec<Win
     Ok(result)
 }
 
+/// Get the window currently focused by the user, if any
+#[pyfunction]
+fn get_active_window() -> PyResult<Option<WindowInfo>> {
+    let Some(id) = active_window_id()? else {
+        return Ok(None);
+    };
+
+    let windows = Window::all().map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get windows: {}", e))
+    })?;
+
+    Ok(windows.into_iter().find(|w| w.id() == id).map(|w| window_to_info(&w)))
+}
+
 /// Capture a specific window by ID
 #[pyfunction]
-fn capture_window(window_id: u32) -> PyResult<Option<CapturedWindow>> {
+#[pyo3(signature = (window_id, format=ImageFormat::Png, quality=None))]
+fn capture_window(
+    window_id: u32,
+    format: ImageFormat,
+    quality: Option<u8>,
+) -> PyResult<Option<CapturedWindow>> {
     let windows = Window::all().map_err(|e| {
         pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get windows: {}", e))
     })?;
@@ -315,13 +898,11 @@ fn capture_window(window_id: u32) -> PyResult<Option<CapturedWindow>> {
                 pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to capture: {}", e))
             })?;
 
-            let image_data = image_to_png_bytes(&image).map_err(|e| {
-                pyo3::exceptions::PyRuntimeError::new_err(e)
-            })?;
-
             return Ok(Some(CapturedWindow {
                 info: window_to_info(&window),
-                image_data,
+                pixels: image,
+                format,
+                quality,
             }));
         }
     }
@@ -331,8 +912,13 @@ fn capture_window(window_id: u32) -> PyResult<Option<CapturedWindow>> {
 
 /// Capture all visible windows
 #[pyfunction]
-#[pyo3(signature = (include_minimized=false, filter_system=true))]
-fn capture_all_windows(include_minimized: bool, filter_system: bool) -> PyResult<Vec<CapturedWindow>> {
+#[pyo3(signature = (include_minimized=false, filter_system=true, format=ImageFormat::Png, quality=None))]
+fn capture_all_windows(
+    include_minimized: bool,
+    filter_system: bool,
+    format: ImageFormat,
+    quality: Option<u8>,
+) -> PyResult<Vec<CapturedWindow>> {
     let windows = Window::all().map_err(|e| {
         pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get windows: {}", e))
     })?;
@@ -352,12 +938,12 @@ fn capture_all_windows(include_minimized: bool, filter_system: bool) -> PyResult
         }
 
         if let Ok(image) = window.capture_image() {
-            if let Ok(image_data) = image_to_png_bytes(&image) {
-                result.push(CapturedWindow {
-                    info: window_to_info(&window),
-                    image_data,
-                });
-            }
+            result.push(CapturedWindow {
+                info: window_to_info(&window),
+                pixels: image,
+                format,
+                quality,
+            });
         }
     }
 
@@ -366,50 +952,561 @@ fn capture_all_windows(include_minimized: bool, filter_system: bool) -> PyResult
 
 /// Capture a specific monitor (full screen)
 #[pyfunction]
-#[pyo3(signature = (monitor_id=None))]
-fn capture_screen(monitor_id: Option<u32>) -> PyResult<Option<CapturedScreen>> {
+#[pyo3(signature = (monitor_id=None, format=ImageFormat::Png, quality=None))]
+fn capture_screen(
+    monitor_id: Option<u32>,
+    format: ImageFormat,
+    quality: Option<u8>,
+) -> PyResult<Option<CapturedScreen>> {
     let monitors = Monitor::all().map_err(|e| {
         pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get monitors: {}", e))
     })?;
 
-    let (index, monitor) = if let Some(id) = monitor_id {
-        monitors
-            .iter()
-            .enumerate()
-            .find(|(_, m)| m.id() == id)
-            .map(|(i, m)| (i, m))
-    } else {
-        monitors.first().map(|m| (0, m))
-    }
-    .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("No monitor found"))?;
+    let (index, monitor) = find_monitor(&monitors, monitor_id)?;
 
     let image = monitor.capture_image().map_err(|e| {
         pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to capture screen: {}", e))
     })?;
 
-    let image_data = image_to_png_bytes(&image).map_err(|e| {
-        pyo3::exceptions::PyRuntimeError::new_err(e)
+    Ok(Some(CapturedScreen {
+        monitor: monitor_to_info(monitor, index as u32),
+        pixels: image,
+        format,
+        quality,
+    }))
+}
+
+/// Translate a desired capture rectangle (in the same logical/point
+/// coordinate space as `Monitor::x/y/width/height`) into pixel coordinates
+/// within that monitor's captured image, clamping to the image's actual
+/// bounds. `image_width`/`image_height` are the captured buffer's pixel
+/// dimensions, which on HiDPI/scaled displays are a multiple of the
+/// monitor's logical `width`/`height` — so the scale factor must be derived
+/// from the real buffer, not assumed to be 1:1.
+///
+/// Returns `(rel_x, rel_y, region_width, region_height)` in pixels.
+fn clamp_region_to_pixels(
+    monitor_x: i32,
+    monitor_y: i32,
+    monitor_width: u32,
+    monitor_height: u32,
+    image_width: u32,
+    image_height: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> (u32, u32, u32, u32) {
+    let scale_x = image_width as f64 / monitor_width.max(1) as f64;
+    let scale_y = image_height as f64 / monitor_height.max(1) as f64;
+
+    let rel_x_logical = (x - monitor_x).max(0) as u32;
+    let rel_y_logical = (y - monitor_y).max(0) as u32;
+    let rel_x_logical = rel_x_logical.min(monitor_width.saturating_sub(1));
+    let rel_y_logical = rel_y_logical.min(monitor_height.saturating_sub(1));
+    let region_width_logical = width.min(monitor_width.saturating_sub(rel_x_logical));
+    let region_height_logical = height.min(monitor_height.saturating_sub(rel_y_logical));
+
+    let rel_x = (rel_x_logical as f64 * scale_x).round() as u32;
+    let rel_y = (rel_y_logical as f64 * scale_y).round() as u32;
+    let region_width = ((region_width_logical as f64 * scale_x).round() as u32)
+        .min(image_width.saturating_sub(rel_x));
+    let region_height = ((region_height_logical as f64 * scale_y).round() as u32)
+        .min(image_height.saturating_sub(rel_y));
+
+    (rel_x, rel_y, region_width, region_height)
+}
+
+/// Capture a rectangular region of the desktop in virtual-screen coordinates.
+///
+/// When `monitor_id` is omitted, the monitor containing `(x, y)` is used —
+/// not the primary monitor — so a region on a secondary display resolves to
+/// the right pixels instead of being silently clamped into the primary.
+#[pyfunction]
+#[pyo3(signature = (x, y, width, height, monitor_id=None, format=ImageFormat::Png, quality=None))]
+fn capture_region(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    monitor_id: Option<u32>,
+    format: ImageFormat,
+    quality: Option<u8>,
+) -> PyResult<Option<CapturedScreen>> {
+    let monitors = Monitor::all().map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get monitors: {}", e))
+    })?;
+
+    let (index, monitor) = match monitor_id {
+        Some(id) => find_monitor(&monitors, Some(id))?,
+        None => find_monitor_for_point(&monitors, x, y)?,
+    };
+
+    let image = monitor.capture_image().map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to capture screen: {}", e))
     })?;
 
+    let (rel_x, rel_y, region_width, region_height) = clamp_region_to_pixels(
+        monitor.x(),
+        monitor.y(),
+        monitor.width(),
+        monitor.height(),
+        image.width(),
+        image.height(),
+        x,
+        y,
+        width,
+        height,
+    );
+
+    let cropped = image::imageops::crop_imm(&image, rel_x, rel_y, region_width, region_height).to_image();
+
+    // rel_x/rel_y are in pixels, which may be a multiple of the monitor's
+    // logical coordinates on HiDPI displays; report the origin back in the
+    // same logical space the request was made in.
+    let scale_x = image.width() as f64 / monitor.width().max(1) as f64;
+    let scale_y = image.height() as f64 / monitor.height().max(1) as f64;
+    let origin_x_logical = (rel_x as f64 / scale_x).round() as i32;
+    let origin_y_logical = (rel_y as f64 / scale_y).round() as i32;
+
     Ok(Some(CapturedScreen {
-        monitor: monitor_to_info(monitor, index as u32),
-        image_data,
+        monitor: MonitorInfo {
+            id: monitor.id(),
+            name: monitor.name().to_string(),
+            x: monitor.x() + origin_x_logical,
+            y: monitor.y() + origin_y_logical,
+            width: region_width,
+            height: region_height,
+            is_primary: index == 0,
+        },
+        pixels: cropped,
+        format,
+        quality,
     }))
 }
 
+/// Compute each monitor's placement (in pixels, scaled from its own
+/// logical-to-pixel ratio) within the union bounding box of all monitors,
+/// plus the resulting canvas size. `monitors` is `(x, y, logical_width,
+/// logical_height, image_width, image_height)` per monitor.
+///
+/// Returns `(canvas_width, canvas_height, offsets)` where `offsets[i]` is the
+/// `(x, y)` pixel offset at which to overlay `monitors[i]`'s captured image.
+fn virtual_screen_layout(monitors: &[(i32, i32, u32, u32, u32, u32)]) -> (u32, u32, Vec<(i64, i64)>) {
+    let min_x = monitors.iter().map(|m| m.0).min().unwrap_or(0);
+    let min_y = monitors.iter().map(|m| m.1).min().unwrap_or(0);
+
+    let offsets: Vec<(i64, i64)> = monitors
+        .iter()
+        .map(|&(x, y, logical_width, logical_height, image_width, image_height)| {
+            let scale_x = image_width as f64 / logical_width.max(1) as f64;
+            let scale_y = image_height as f64 / logical_height.max(1) as f64;
+            let offset_x = ((x - min_x) as f64 * scale_x).round() as i64;
+            let offset_y = ((y - min_y) as f64 * scale_y).round() as i64;
+            (offset_x, offset_y)
+        })
+        .collect();
+
+    let canvas_width = monitors
+        .iter()
+        .zip(&offsets)
+        .map(|(m, (ox, _))| ox + m.4 as i64)
+        .max()
+        .unwrap_or(0) as u32;
+    let canvas_height = monitors
+        .iter()
+        .zip(&offsets)
+        .map(|(m, (_, oy))| oy + m.5 as i64)
+        .max()
+        .unwrap_or(0) as u32;
+
+    (canvas_width, canvas_height, offsets)
+}
+
+/// Capture every monitor and stitch them into one coherent virtual-desktop image
+#[pyfunction]
+#[pyo3(signature = (format=ImageFormat::Png, quality=None))]
+fn capture_virtual_screen(format: ImageFormat, quality: Option<u8>) -> PyResult<CapturedScreen> {
+    let monitors = Monitor::all().map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to get monitors: {}", e))
+    })?;
+
+    if monitors.is_empty() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err("No monitor found"));
+    }
+
+    let min_x = monitors.iter().map(|m| m.x()).min().unwrap();
+    let min_y = monitors.iter().map(|m| m.y()).min().unwrap();
+
+    let images: Vec<image::RgbaImage> = monitors
+        .iter()
+        .map(|monitor| {
+            monitor.capture_image().map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to capture screen: {}", e))
+            })
+        })
+        .collect::<PyResult<_>>()?;
+
+    let layout_input: Vec<(i32, i32, u32, u32, u32, u32)> = monitors
+        .iter()
+        .zip(&images)
+        .map(|(m, image)| (m.x(), m.y(), m.width(), m.height(), image.width(), image.height()))
+        .collect();
+    let (canvas_width, canvas_height, offsets) = virtual_screen_layout(&layout_input);
+
+    let mut canvas = image::RgbaImage::new(canvas_width, canvas_height);
+    for (image, (offset_x, offset_y)) in images.into_iter().zip(offsets) {
+        image::imageops::overlay(&mut canvas, &image, offset_x, offset_y);
+    }
+
+    Ok(CapturedScreen {
+        monitor: MonitorInfo {
+            id: 0,
+            name: "virtual-screen".to_string(),
+            x: min_x,
+            y: min_y,
+            width: canvas_width,
+            height: canvas_height,
+            is_primary: false,
+        },
+        pixels: canvas,
+        format,
+        quality,
+    })
+}
+
 /// Capture full screen and all visible windows in one call
 #[pyfunction]
-#[pyo3(signature = (monitor_id=None, include_minimized=false, filter_system=true))]
+#[pyo3(signature = (monitor_id=None, include_minimized=false, filter_system=true, format=ImageFormat::Png, quality=None))]
 fn capture_screen_with_windows(
     monitor_id: Option<u32>,
     include_minimized: bool,
     filter_system: bool,
+    format: ImageFormat,
+    quality: Option<u8>,
 ) -> PyResult<(Option<CapturedScreen>, Vec<CapturedWindow>)> {
-    let screen = capture_screen(monitor_id)?;
-    let windows = capture_all_windows(include_minimized, filter_system)?;
+    let screen = capture_screen(monitor_id, format, quality)?;
+    let windows = capture_all_windows(include_minimized, filter_system, format, quality)?;
     Ok((screen, windows))
 }
 
+/// Capture the window currently focused by the user, if any
+#[pyfunction]
+#[pyo3(signature = (format=ImageFormat::Png, quality=None))]
+fn capture_active_window(format: ImageFormat, quality: Option<u8>) -> PyResult<Option<CapturedWindow>> {
+    match get_active_window()? {
+        Some(info) => capture_window(info.id, format, quality),
+        None => Ok(None),
+    }
+}
+
+// ============================================================================
+// Continuous capture stream
+// ============================================================================
+
+/// What a `CaptureStream` is repeatedly capturing
+#[derive(Clone, Copy)]
+enum StreamTarget {
+    Monitor(u32),
+    Window(u32),
+    ActiveWindow,
+}
+
+/// A single frame produced by a `CaptureStream`
+#[pyclass]
+#[derive(Clone)]
+pub struct StreamFrame {
+    #[pyo3(get)]
+    pub width: u32,
+    #[pyo3(get)]
+    pub height: u32,
+    /// Set when the stream noticed the target changed geometry, disappeared,
+    /// or a monitor was plugged/unplugged since the previous frame.
+    #[pyo3(get)]
+    pub stale: bool,
+    pixels: image::RgbaImage,
+    format: ImageFormat,
+    quality: Option<u8>,
+}
+
+#[pymethods]
+impl StreamFrame {
+    /// Get image bytes, encoded in the stream's configured format
+    fn get_image_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let image_data = image_to_bytes(&self.pixels, self.format, self.quality)
+            .map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
+        Ok(PyBytes::new(py, &image_data))
+    }
+
+    /// Get the raw RGBA8 pixel buffer with no encoding. The pixels are copied
+    /// into a new `RawFrame`, whose buffer Python can then wrap without a
+    /// further copy.
+    fn get_image_raw(&self) -> RawFrame {
+        image_to_raw_frame(&self.pixels)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "StreamFrame(size={}x{}, stale={})",
+            self.width, self.height, self.stale
+        )
+    }
+}
+
+fn capture_stream_target(target: StreamTarget) -> Option<(image::RgbaImage, (i32, i32, u32, u32))> {
+    match target {
+        StreamTarget::Monitor(id) => {
+            let monitors = Monitor::all().ok()?;
+            let monitor = monitors.into_iter().find(|m| m.id() == id)?;
+            let image = monitor.capture_image().ok()?;
+            Some((image, (monitor.x(), monitor.y(), monitor.width(), monitor.height())))
+        }
+        StreamTarget::Window(id) => {
+            let windows = Window::all().ok()?;
+            let window = windows.into_iter().find(|w| w.id() == id)?;
+            let image = window.capture_image().ok()?;
+            Some((image, (window.x(), window.y(), window.width(), window.height())))
+        }
+        StreamTarget::ActiveWindow => {
+            let id = active_window_id().ok()??;
+            capture_stream_target(StreamTarget::Window(id))
+        }
+    }
+}
+
+/// Push `item` onto a bounded ring buffer, evicting the oldest entry first if
+/// the buffer is already at `capacity`.
+fn push_bounded<T>(queue: &mut VecDeque<T>, item: T, capacity: usize) {
+    if queue.len() >= capacity.max(1) {
+        queue.pop_front();
+    }
+    queue.push_back(item);
+}
+
+/// State shared between the background capture thread and the Python-facing
+/// `CaptureStream`: a bounded frame queue plus a `finished` flag so readers
+/// waiting on the condvar wake up (instead of blocking forever) once the
+/// producer thread has exited.
+struct StreamShared {
+    queue: Mutex<VecDeque<StreamFrame>>,
+    condvar: Condvar,
+    finished: AtomicBool,
+}
+
+impl StreamShared {
+    fn push(&self, frame: StreamFrame, capacity: usize) {
+        let mut queue = self.queue.lock().unwrap();
+        push_bounded(&mut queue, frame, capacity);
+        self.condvar.notify_one();
+    }
+
+    fn mark_finished(&self) {
+        self.finished.store(true, Ordering::Release);
+        self.condvar.notify_all();
+    }
+}
+
+/// How many consecutive failed ticks an `ActiveWindow` stream tolerates
+/// before treating the target as really gone. Focus can momentarily land on
+/// the desktop, or a just-focused window can lose the enumeration race
+/// against `Window::all()`, for a tick or two — that's a gap to skip, not a
+/// disappearance that should kill the thread.
+const ACTIVE_WINDOW_MISS_TOLERANCE: u32 = 3;
+
+/// Background-thread capture loop shared by `CaptureStream`
+fn run_capture_stream(
+    target: StreamTarget,
+    frame_interval: Duration,
+    format: ImageFormat,
+    quality: Option<u8>,
+    shared: Arc<StreamShared>,
+    buffer_size: usize,
+    stop: Arc<AtomicBool>,
+) {
+    let mut last_geometry: Option<(i32, i32, u32, u32)> = None;
+    let mut monitor_count = Monitor::all().map(|m| m.len()).unwrap_or(0);
+    let mut consecutive_misses: u32 = 0;
+
+    while !stop.load(Ordering::Relaxed) {
+        let tick_start = Instant::now();
+
+        let current_monitor_count = Monitor::all().map(|m| m.len()).unwrap_or(monitor_count);
+        let hotplug_changed = current_monitor_count != monitor_count;
+        monitor_count = current_monitor_count;
+
+        if let Some((image, geometry)) = capture_stream_target(target) {
+            consecutive_misses = 0;
+            let target_changed = last_geometry.is_some_and(|g| g != geometry);
+            last_geometry = Some(geometry);
+
+            let frame = StreamFrame {
+                width: image.width(),
+                height: image.height(),
+                stale: hotplug_changed || target_changed,
+                pixels: image,
+                format,
+                quality,
+            };
+
+            shared.push(frame, buffer_size);
+        } else if matches!(target, StreamTarget::ActiveWindow)
+            && consecutive_misses < ACTIVE_WINDOW_MISS_TOLERANCE
+        {
+            // Focus briefly had no resolvable window: skip this tick rather
+            // than treating it as the target disappearing.
+            consecutive_misses += 1;
+        } else {
+            // The target disappeared (window closed, monitor unplugged), or
+            // an ActiveWindow stream failed to resolve a focused window for
+            // too many ticks in a row: surface one stale marker frame from
+            // the last known geometry, then stop.
+            if let Some((_, _, width, height)) = last_geometry {
+                let frame = StreamFrame {
+                    width,
+                    height,
+                    stale: true,
+                    pixels: image::RgbaImage::new(width, height),
+                    format,
+                    quality,
+                };
+                shared.push(frame, buffer_size);
+            }
+            break;
+        }
+
+        let elapsed = tick_start.elapsed();
+        if elapsed < frame_interval {
+            std::thread::sleep(frame_interval - elapsed);
+        }
+    }
+
+    // Whatever the exit reason (stop() called, or the target disappearing
+    // above), wake any reader blocked in next_frame so it gets StopIteration
+    // instead of waiting on a condvar nothing will ever notify again.
+    shared.mark_finished();
+}
+
+/// Continuously captures a monitor, window, or the active window on a
+/// background thread into a bounded ring buffer, so Python can pull frames
+/// without re-enumerating windows/monitors every tick.
+#[pyclass]
+pub struct CaptureStream {
+    shared: Arc<StreamShared>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[pymethods]
+impl CaptureStream {
+    #[new]
+    #[pyo3(signature = (monitor_id=None, window_id=None, active_window=false, fps=10.0, buffer_size=4, format=ImageFormat::Png, quality=None))]
+    fn new(
+        monitor_id: Option<u32>,
+        window_id: Option<u32>,
+        active_window: bool,
+        fps: f64,
+        buffer_size: usize,
+        format: ImageFormat,
+        quality: Option<u8>,
+    ) -> PyResult<Self> {
+        let target = match (monitor_id, window_id, active_window) {
+            (Some(id), None, false) => StreamTarget::Monitor(id),
+            (None, Some(id), false) => StreamTarget::Window(id),
+            (None, None, true) => StreamTarget::ActiveWindow,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Specify exactly one of monitor_id, window_id, or active_window=True",
+                ))
+            }
+        };
+
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "fps must be a finite positive number",
+            ));
+        }
+
+        let shared = Arc::new(StreamShared {
+            queue: Mutex::new(VecDeque::with_capacity(buffer_size)),
+            condvar: Condvar::new(),
+            finished: AtomicBool::new(false),
+        });
+        let stop = Arc::new(AtomicBool::new(false));
+        let frame_interval = Duration::from_secs_f64(1.0 / fps);
+
+        let thread_shared = shared.clone();
+        let thread_stop = stop.clone();
+        let handle = std::thread::spawn(move || {
+            run_capture_stream(target, frame_interval, format, quality, thread_shared, buffer_size, thread_stop);
+        });
+
+        Ok(CaptureStream {
+            shared,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Block for up to `timeout` seconds (or forever if `None`) for the next
+    /// frame, releasing the GIL while waiting. Returns `None` once the
+    /// stream has finished (target gone, or `stop()` called) and drained.
+    /// Raises `TimeoutError` if `timeout` elapses with the stream still
+    /// running but no frame available, so a slow frame is reported by this
+    /// exception and a `None` return always means end-of-stream.
+    #[pyo3(signature = (timeout=None))]
+    fn next_frame(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Option<StreamFrame>> {
+        let shared = self.shared.clone();
+        py.allow_threads(move || {
+            let queue = shared.queue.lock().unwrap();
+            let should_wait = |q: &mut VecDeque<StreamFrame>| {
+                q.is_empty() && !shared.finished.load(Ordering::Acquire)
+            };
+            let mut queue = match timeout {
+                Some(t) => {
+                    shared
+                        .condvar
+                        .wait_timeout_while(queue, Duration::from_secs_f64(t), should_wait)
+                        .unwrap()
+                        .0
+                }
+                None => shared.condvar.wait_while(queue, should_wait).unwrap(),
+            };
+            match queue.pop_front() {
+                Some(frame) => Ok(Some(frame)),
+                None if shared.finished.load(Ordering::Acquire) => Ok(None),
+                None => Err(pyo3::exceptions::PyTimeoutError::new_err(
+                    "timed out waiting for next frame",
+                )),
+            }
+        })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<StreamFrame>> {
+        self.next_frame(py, None)
+    }
+
+    /// Stop the background capture thread
+    fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for CaptureStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
 // ============================================================================
 // Python module definition (PyO3 0.20 API)
 // ============================================================================
@@ -420,14 +1517,114 @@ fn screencap_rs(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<MonitorInfo>()?;
     m.add_class::<CapturedWindow>()?;
     m.add_class::<CapturedScreen>()?;
+    m.add_class::<RawFrame>()?;
+    m.add_class::<ImageFormat>()?;
+    m.add_class::<StreamFrame>()?;
+    m.add_class::<CaptureStream>()?;
 
     m.add_function(wrap_pyfunction!(get_platform, m)?)?;
     m.add_function(wrap_pyfunction!(get_monitors, m)?)?;
     m.add_function(wrap_pyfunction!(get_windows, m)?)?;
+    m.add_function(wrap_pyfunction!(get_active_window, m)?)?;
     m.add_function(wrap_pyfunction!(capture_window, m)?)?;
+    m.add_function(wrap_pyfunction!(capture_active_window, m)?)?;
     m.add_function(wrap_pyfunction!(capture_all_windows, m)?)?;
     m.add_function(wrap_pyfunction!(capture_screen, m)?)?;
+    m.add_function(wrap_pyfunction!(capture_region, m)?)?;
+    m.add_function(wrap_pyfunction!(capture_virtual_screen, m)?)?;
     m.add_function(wrap_pyfunction!(capture_screen_with_windows, m)?)?;
+    m.add_function(wrap_pyfunction!(focus_window, m)?)?;
+    m.add_function(wrap_pyfunction!(minimize_window, m)?)?;
+    m.add_function(wrap_pyfunction!(restore_window, m)?)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_bounded_evicts_oldest_once_at_capacity() {
+        let mut queue: VecDeque<u32> = VecDeque::new();
+        for i in 0..4 {
+            push_bounded(&mut queue, i, 3);
+        }
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn push_bounded_below_capacity_keeps_everything() {
+        let mut queue: VecDeque<u32> = VecDeque::new();
+        push_bounded(&mut queue, 1, 3);
+        push_bounded(&mut queue, 2, 3);
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn push_bounded_treats_zero_capacity_as_one() {
+        let mut queue: VecDeque<u32> = VecDeque::new();
+        push_bounded(&mut queue, 1, 0);
+        push_bounded(&mut queue, 2, 0);
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn clamp_region_to_pixels_is_identity_at_1x_scale() {
+        let (rel_x, rel_y, width, height) =
+            clamp_region_to_pixels(0, 0, 1920, 1080, 1920, 1080, 100, 200, 300, 400);
+        assert_eq!((rel_x, rel_y, width, height), (100, 200, 300, 400));
+    }
+
+    #[test]
+    fn clamp_region_to_pixels_scales_for_hidpi() {
+        // A 2x-scaled display: logical 1000x800 maps to a 2000x1600 pixel buffer.
+        let (rel_x, rel_y, width, height) =
+            clamp_region_to_pixels(0, 0, 1000, 800, 2000, 1600, 100, 50, 200, 100);
+        assert_eq!((rel_x, rel_y, width, height), (200, 100, 400, 200));
+    }
+
+    #[test]
+    fn clamp_region_to_pixels_clamps_to_monitor_and_image_bounds() {
+        let (rel_x, rel_y, width, height) =
+            clamp_region_to_pixels(0, 0, 1000, 800, 2000, 1600, 900, 750, 500, 500);
+        // Logical rect is clamped to the monitor first, then scaled to pixels.
+        assert_eq!(rel_x, 1800);
+        assert_eq!(rel_y, 1500);
+        assert!(rel_x + width <= 2000);
+        assert!(rel_y + height <= 1600);
+    }
+
+    #[test]
+    fn clamp_region_to_pixels_accounts_for_monitor_origin() {
+        let (rel_x, rel_y, _, _) =
+            clamp_region_to_pixels(500, 300, 1920, 1080, 1920, 1080, 600, 350, 100, 100);
+        assert_eq!((rel_x, rel_y), (100, 50));
+    }
+
+    #[test]
+    fn virtual_screen_layout_tiles_monitors_at_1x_scale() {
+        let monitors = vec![(0, 0, 1920, 1080, 1920, 1080), (1920, 0, 1920, 1080, 1920, 1080)];
+        let (width, height, offsets) = virtual_screen_layout(&monitors);
+        assert_eq!((width, height), (3840, 1080));
+        assert_eq!(offsets, vec![(0, 0), (1920, 0)]);
+    }
+
+    #[test]
+    fn virtual_screen_layout_handles_negative_origin_monitors() {
+        // Secondary monitor positioned to the left of and above the primary.
+        let monitors = vec![(-1920, -200, 1920, 1080, 1920, 1080), (0, 0, 1920, 1080, 1920, 1080)];
+        let (width, height, offsets) = virtual_screen_layout(&monitors);
+        assert_eq!(offsets, vec![(0, 0), (1920, 200)]);
+        assert_eq!((width, height), (3840, 1280));
+    }
+
+    #[test]
+    fn virtual_screen_layout_scales_each_monitor_by_its_own_dpi() {
+        // Primary monitor is HiDPI (2x); secondary is 1x and sits at logical x=1000.
+        let monitors = vec![(0, 0, 1000, 800, 2000, 1600), (1000, 0, 1920, 1080, 1920, 1080)];
+        let (width, height, offsets) = virtual_screen_layout(&monitors);
+        assert_eq!(offsets, vec![(0, 0), (1000, 0)]);
+        assert_eq!((width, height), (2920, 1600));
+    }
+}